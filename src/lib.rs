@@ -23,7 +23,7 @@ impl Display for Error {
 impl std::error::Error for Error {}
 
 /// A type representing a valid IMEI
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Imei<I> {
     inner: I,
 }
@@ -48,6 +48,22 @@ impl<I: AsRef<str>> Imei<I> {
             Err(Error::InvalidImei)
         }
     }
+
+    /// Get the Type Allocation Code, the first 8 digits identifying the
+    /// device's make and model.
+    pub fn tac(&self) -> &str {
+        &self.inner.as_ref()[..8]
+    }
+
+    /// Get the serial number, the 6 digits after the [TAC](Self::tac).
+    pub fn serial(&self) -> &str {
+        &self.inner.as_ref()[8..14]
+    }
+
+    /// Get the Luhn check digit, the final digit of the IMEI.
+    pub fn check_digit(&self) -> u8 {
+        self.inner.as_ref().as_bytes()[14] - b'0'
+    }
 }
 
 impl<I: AsRef<str>> Display for Imei<I> {
@@ -56,12 +72,75 @@ impl<I: AsRef<str>> Display for Imei<I> {
     }
 }
 
+impl<I> core::str::FromStr for Imei<I>
+where
+    for<'s> I: From<&'s str>,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if valid(s) {
+            Ok(Self { inner: I::from(s) })
+        } else {
+            Err(Error::InvalidImei)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Imei<String> {
+    /// Generate a valid [`Imei`] from a 14-digit prefix (TAC + serial) by
+    /// computing and appending the correct Luhn check digit. Useful for
+    /// generating test data or completing IMEIs stored without their check
+    /// digit.
+    pub fn complete(partial14: &str) -> Result<Self, Error> {
+        let digits = partial14.as_bytes();
+
+        if digits.len() != 14 || !digits.iter().all(u8::is_ascii_digit) {
+            return Err(Error::InvalidImei);
+        }
+
+        // the same odd-position-doubling Luhn rule as `valid`, just without
+        // a check digit to fold in yet
+        let mut sum: u8 = 0;
+        for (i, &digit) in digits.iter().enumerate() {
+            let mut n = digit - b'0';
+            if (i + 1) % 2 == 0 {
+                n *= 2;
+                if n > 9 {
+                    n -= 9;
+                }
+            }
+            sum += n;
+        }
+
+        let check_digit = (10 - sum % 10) % 10;
+
+        Ok(Self {
+            inner: format!("{partial14}{check_digit}"),
+        })
+    }
+}
+
 /// Check to see if an IMEI number is valid.
 pub fn valid<A: AsRef<str>>(imei: A) -> bool {
     let s = imei.as_ref();
 
     // str::len is acceptable because if s is numeric (therefore valid),
     //   there will not be issues with UTF-8
+    match <&[u8; 15]>::try_from(s.as_bytes()) {
+        // the common case: exactly 15 bytes, so we can take the branchless
+        // SWAR fast path instead of walking the string char-by-char
+        Ok(bytes) => valid_swar(bytes),
+        // anything else (wrong length) falls back to the scalar routine,
+        // which also rejects it, without ever touching the SWAR path
+        Err(_) => valid_scalar(s),
+    }
+}
+
+/// The original char-by-char Luhn check, kept as the fallback for inputs
+/// that aren't exactly 15 bytes long.
+fn valid_scalar(s: &str) -> bool {
     if s.len() != 15 {
         return false;
     }
@@ -120,6 +199,113 @@ pub fn valid<A: AsRef<str>>(imei: A) -> bool {
     sum % 10 == 0
 }
 
+/// Branchless SWAR (SIMD-within-a-register) validation of a 15-byte ASCII
+/// IMEI. This is the same Luhn check as [`valid_scalar`], but it processes
+/// 8 digits at a time as a `u64` instead of branching on every character.
+fn valid_swar(bytes: &[u8; 15]) -> bool {
+    // pack the 15 digits into two 8-byte little-endian words, padding the
+    // last one with an extra '0' so both chunks can be handled identically
+    let mut padded = [b'0'; 16];
+    padded[..15].copy_from_slice(bytes);
+    let w1 = u64::from_le_bytes(padded[0..8].try_into().unwrap());
+    let w2 = u64::from_le_bytes(padded[8..16].try_into().unwrap());
+
+    // 0x46 = 0x7F - '9', see the hex-decoding SWAR trick this is borrowed from
+    const ASCII_HI: u64 = 0x4646464646464646;
+    const ASCII_LO: u64 = 0x3030303030303030;
+    const HIGH_BIT: u64 = 0x8080808080808080;
+    const NIBBLE: u64 = 0x0f0f0f0f0f0f0f0f;
+    // the Luhn algorithm doubles every other digit, starting at index 1;
+    // since 8 is even this same lane pattern applies to both words
+    const DOUBLED_LANES: u64 = 0xff00ff00ff00ff00;
+    const ADD_THREE: u64 = 0x0303030303030303;
+    const BIT_THREE: u64 = 0x0808080808080808;
+    const ONES: u64 = 0x0101010101010101;
+
+    // verify all 8 bytes of a word are ASCII digits in one shot
+    let all_digits = |w: u64| (w.wrapping_add(ASCII_HI) | w.wrapping_sub(ASCII_LO)) & HIGH_BIT == 0;
+
+    if !all_digits(w1) || !all_digits(w2) {
+        return false;
+    }
+
+    // sum the (possibly doubled) digit values of one word
+    let lane_sum = |w: u64| -> u64 {
+        let digit = w & NIBBLE;
+
+        // doubled digit value is `2*d - 9*(d > 4)`; `d > 4` is computed as
+        // bit 3 of `d + 3`, which never carries since `d <= 9`
+        let is_ge_five = (digit.wrapping_add(ADD_THREE) & BIT_THREE) >> 3;
+        let reduction = is_ge_five.wrapping_add(is_ge_five << 3);
+        let doubled = (digit << 1).wrapping_sub(reduction);
+
+        let selected = (digit & !DOUBLED_LANES) | (doubled & DOUBLED_LANES);
+
+        // horizontal byte sum: total maxes out at 8 * 9 = 72, well under 256
+        selected.wrapping_mul(ONES) >> 56
+    };
+
+    (lane_sum(w1) + lane_sum(w2)) % 10 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // small xorshift PRNG so the test is deterministic without an extra dependency
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn swar_matches_scalar_on_random_digits() {
+        let mut state = 0x9e3779b97f4a7c15u64;
+
+        for _ in 0..100_000 {
+            let mut buf = [0u8; 15];
+            for b in buf.iter_mut() {
+                *b = b'0' + (next(&mut state) % 10) as u8;
+            }
+            let s = core::str::from_utf8(&buf).unwrap();
+
+            assert_eq!(valid_scalar(s), valid_swar(&buf), "mismatch for {s}");
+        }
+    }
+
+    #[test]
+    fn swar_matches_scalar_on_non_digit_and_non_ascii_bytes() {
+        let mut state = 0xd1b54a32d192ed03u64;
+
+        for _ in 0..100_000 {
+            let mut buf = [0u8; 15];
+            for b in buf.iter_mut() {
+                *b = (next(&mut state) % 256) as u8;
+            }
+
+            // valid_scalar works on &str, so skip samples that aren't valid UTF-8;
+            // valid_swar operates on raw bytes either way
+            if let Ok(s) = core::str::from_utf8(&buf) {
+                assert_eq!(valid_scalar(s), valid_swar(&buf), "mismatch for {buf:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn swar_agrees_on_known_valid_imeis() {
+        for imei in ["490154203237518", "354406185514933", "522872587498800"] {
+            let bytes: &[u8; 15] = imei.as_bytes().try_into().unwrap();
+            assert!(valid_swar(bytes));
+            assert!(valid_scalar(imei));
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use serde::{deserialize_with, MaybeImei};
+
 #[cfg(feature = "serde")]
 mod serde {
     use crate::{valid, Error, Imei};
@@ -171,6 +357,32 @@ mod serde {
                         Err(E::custom(Error::InvalidImei))
                     }
                 }
+
+                fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    self.visit_str(&v.to_string())
+                }
+
+                fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    // a negative number can never be a valid IMEI
+                    if v < 0 {
+                        return Err(E::custom(Error::InvalidImei));
+                    }
+
+                    self.visit_str(&v.to_string())
+                }
+
+                fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    self.visit_str(&v.to_string())
+                }
             }
 
             let visitor: ImeiVisitor<I> = ImeiVisitor {
@@ -180,6 +392,149 @@ mod serde {
             deserializer.deserialize_any(visitor)
         }
     }
+
+    /// A lenient counterpart to [`Imei`] for fields that may be missing or
+    /// hold an invalid IMEI. Unlike [`Imei`]'s [`Deserialize`] impl, this
+    /// does not fail on a string, an integer or `null`: it records whatever
+    /// was found instead of aborting the whole struct, so callers can collect
+    /// and report every bad IMEI in a batch rather than failing on the first.
+    /// Any other type of value (a float, a bool, a sequence or a map) is
+    /// still a deserialization error.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum MaybeImei<I> {
+        /// The field was present and held a valid IMEI.
+        Parsed(Imei<I>),
+        /// The field was present, but didn't hold a valid IMEI.
+        FailedToParse(String),
+        /// The field was missing entirely, or was `null`.
+        NotPresent,
+    }
+
+    impl<I> Default for MaybeImei<I> {
+        /// Maps to [`MaybeImei::NotPresent`], so `#[serde(default)]` can be
+        /// used to handle a missing field.
+        fn default() -> Self {
+            MaybeImei::NotPresent
+        }
+    }
+
+    impl<I> MaybeImei<I> {
+        /// Get the [`Imei`], if the field was present and valid.
+        pub fn as_valid(&self) -> Option<&Imei<I>> {
+            match self {
+                MaybeImei::Parsed(imei) => Some(imei),
+                _ => None,
+            }
+        }
+
+        /// Whether the field held a value at all, valid or not (a `null`
+        /// counts as not present).
+        pub fn is_present(&self) -> bool {
+            !matches!(self, MaybeImei::NotPresent)
+        }
+
+        /// The raw value as it was received, if the field was present.
+        pub fn raw(&self) -> Option<&str>
+        where
+            I: AsRef<str>,
+        {
+            match self {
+                MaybeImei::Parsed(imei) => Some(imei.inner.as_ref()),
+                MaybeImei::FailedToParse(raw) => Some(raw),
+                MaybeImei::NotPresent => None,
+            }
+        }
+    }
+
+    impl<'de, I> Deserialize<'de> for MaybeImei<I>
+    where
+        for<'s> I: From<&'s str>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            use std::fmt;
+            use std::marker::PhantomData;
+            struct MaybeImeiVisitor<I> {
+                _marker: PhantomData<I>,
+            }
+
+            impl<'d, I> Visitor<'d> for MaybeImeiVisitor<I>
+            where
+                for<'s> I: From<&'s str>,
+            {
+                type Value = MaybeImei<I>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("a string or integer, whether or not it's a valid IMEI")
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    Ok(if valid(v) {
+                        MaybeImei::Parsed(Imei { inner: I::from(v) })
+                    } else {
+                        MaybeImei::FailedToParse(v.to_string())
+                    })
+                }
+
+                fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    self.visit_str(&v.to_string())
+                }
+
+                fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    self.visit_str(&v.to_string())
+                }
+
+                fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    self.visit_str(&v.to_string())
+                }
+
+                fn visit_unit<E>(self) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    // a JSON `null` IMEI field is present but empty, not a
+                    // malformed value, so treat it the same as an absent field
+                    Ok(MaybeImei::NotPresent)
+                }
+            }
+
+            let visitor: MaybeImeiVisitor<I> = MaybeImeiVisitor {
+                _marker: PhantomData,
+            };
+
+            deserializer.deserialize_any(visitor)
+        }
+    }
+
+    /// Leniently deserialize an `Option<Imei<I>>` field with
+    /// `#[serde(default, deserialize_with = "imei::deserialize_with")]`,
+    /// collapsing a missing or invalid IMEI to `None` instead of aborting
+    /// the whole struct, without having to change the field's type to
+    /// [`MaybeImei`].
+    pub fn deserialize_with<'de, D, I>(deserializer: D) -> Result<Option<Imei<I>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        for<'s> I: From<&'s str>,
+    {
+        match MaybeImei::deserialize(deserializer)? {
+            MaybeImei::Parsed(imei) => Ok(Some(imei)),
+            MaybeImei::FailedToParse(_) | MaybeImei::NotPresent => Ok(None),
+        }
+    }
 }
 
 #[cfg(feature = "openapi")]
@@ -220,3 +575,53 @@ mod openapi {
         }
     }
 }
+
+/// [`openapiv3`] integration, independent of the `openapi` (utoipa) feature
+/// so either or both can be enabled.
+#[cfg(feature = "openapiv3")]
+pub mod openapi_v3 {
+    use indexmap::IndexMap;
+    use openapiv3::{
+        MediaType, ReferenceOr, Response, Schema, SchemaData, SchemaKind, StatusCode, StringType,
+        Type,
+    };
+
+    /// An [`openapiv3::Schema`] describing a valid IMEI: a 15-digit string.
+    pub fn schema() -> ReferenceOr<Schema> {
+        ReferenceOr::Item(Schema {
+            schema_data: SchemaData {
+                example: Some(serde_json::json!("522872587498800")),
+                ..Default::default()
+            },
+            schema_kind: SchemaKind::Type(Type::String(StringType {
+                pattern: Some(r"^\d{15}$".to_string()),
+                min_length: Some(15),
+                max_length: Some(15),
+                ..Default::default()
+            })),
+        })
+    }
+
+    /// A `200 OK` [`openapiv3::Response`] describing a successful IMEI
+    /// validation, paired with the [`StatusCode`] it should be keyed by in
+    /// an [`openapiv3::Responses`].
+    pub fn response() -> (StatusCode, ReferenceOr<Response>) {
+        let mut content = IndexMap::new();
+        content.insert(
+            "application/json".to_string(),
+            MediaType {
+                schema: Some(schema()),
+                ..Default::default()
+            },
+        );
+
+        (
+            StatusCode::Code(200),
+            ReferenceOr::Item(Response {
+                description: "A valid International Mobile Equipment Identity number".to_string(),
+                content,
+                ..Default::default()
+            }),
+        )
+    }
+}
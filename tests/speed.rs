@@ -51,3 +51,114 @@ fn test_serde() {
     let imei: Imei<String> = serde_json::from_str("\"354406185514933\"").unwrap();
     assert_eq!(imei, Imei::try_new("354406185514933".to_string()).unwrap())
 }
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serde_integer() {
+    use imei::Imei;
+
+    let expected = Imei::try_new("354406185514933".to_string()).unwrap();
+
+    let from_string: Imei<String> = serde_json::from_str("\"354406185514933\"").unwrap();
+    assert_eq!(from_string, expected);
+
+    let from_integer: Imei<String> = serde_json::from_str("354406185514933").unwrap();
+    assert_eq!(from_integer, expected);
+
+    // negative numbers and the wrong number of digits should never zero-pad
+    assert!(serde_json::from_str::<Imei<String>>("-354406185514933").is_err());
+    assert!(serde_json::from_str::<Imei<String>>("3544061855149").is_err());
+    assert!(serde_json::from_str::<Imei<String>>("12345").is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_maybe_imei() {
+    use imei::{Imei, MaybeImei};
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct Batch {
+        #[serde(default)]
+        imei: MaybeImei<String>,
+    }
+
+    let valid: Batch = serde_json::from_str(r#"{"imei": "354406185514933"}"#).unwrap();
+    assert_eq!(
+        valid.imei.as_valid(),
+        Some(&Imei::try_new("354406185514933".to_string()).unwrap())
+    );
+    assert!(valid.imei.is_present());
+    assert_eq!(valid.imei.raw(), Some("354406185514933"));
+
+    let invalid: Batch = serde_json::from_str(r#"{"imei": "not-an-imei"}"#).unwrap();
+    assert_eq!(invalid.imei.as_valid(), None);
+    assert!(invalid.imei.is_present());
+    assert_eq!(invalid.imei.raw(), Some("not-an-imei"));
+
+    let missing: Batch = serde_json::from_str("{}").unwrap();
+    assert_eq!(missing.imei, MaybeImei::NotPresent);
+    assert!(!missing.imei.is_present());
+    assert_eq!(missing.imei.raw(), None);
+
+    // an explicit `null` is present-but-empty, not malformed, so it should
+    // never abort deserialization either
+    let null: Batch = serde_json::from_str(r#"{"imei": null}"#).unwrap();
+    assert_eq!(null.imei, MaybeImei::NotPresent);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_deserialize_with() {
+    use imei::Imei;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct Batch {
+        #[serde(default, deserialize_with = "imei::deserialize_with")]
+        imei: Option<Imei<String>>,
+    }
+
+    let valid: Batch = serde_json::from_str(r#"{"imei": "354406185514933"}"#).unwrap();
+    assert_eq!(
+        valid.imei,
+        Some(Imei::try_new("354406185514933".to_string()).unwrap())
+    );
+
+    let invalid: Batch = serde_json::from_str(r#"{"imei": "not-an-imei"}"#).unwrap();
+    assert_eq!(invalid.imei, None);
+
+    let missing: Batch = serde_json::from_str("{}").unwrap();
+    assert_eq!(missing.imei, None);
+}
+
+#[test]
+fn test_from_str() {
+    use imei::Imei;
+
+    let imei: Imei<String> = "490154203237518".parse().unwrap();
+    assert_eq!(imei, Imei::try_new("490154203237518".to_string()).unwrap());
+
+    assert!("not-an-imei".parse::<Imei<String>>().is_err());
+}
+
+#[test]
+fn test_components() {
+    use imei::Imei;
+
+    let imei = Imei::try_new("490154203237518").unwrap();
+    assert_eq!(imei.tac(), "49015420");
+    assert_eq!(imei.serial(), "323751");
+    assert_eq!(imei.check_digit(), 8);
+}
+
+#[test]
+fn test_complete() {
+    use imei::Imei;
+
+    let imei = Imei::complete("49015420323751").unwrap();
+    assert_eq!(imei, Imei::try_new("490154203237518".to_string()).unwrap());
+
+    assert!(Imei::complete("not-14-digits").is_err());
+    assert!(Imei::complete("123").is_err());
+}